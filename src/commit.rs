@@ -3,20 +3,33 @@
 pub mod simple;
 pub mod typed;
 
-use crate::component::{Body, Description, Scope, Trailer, Type};
+use crate::component::{Body, Description, FooterToken, Scope, Trailer, Type};
 use crate::error::Error;
 use crate::parser::parse;
-use nom::error::VerboseError;
 use std::fmt;
 
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 /// A conventional commit.
+///
+/// With the `serde` feature enabled, this serializes to an object with `ty`,
+/// `scope`, `description`, `body`, `breaking`, `breaking_description`, and
+/// `trailers` fields, omitting any that are `None`. `breaking_description` is
+/// included alongside `breaking` so changelog tooling can render the
+/// breaking-change text without re-deriving it from `trailers`.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
 pub struct Commit<'a> {
     ty: Type<'a>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     scope: Option<Scope<'a>>,
     description: Description<'a>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
     body: Option<Body<'a>>,
     breaking: bool,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    breaking_description: Option<&'a str>,
     trailers: Vec<Trailer<'a>>,
 }
 
@@ -30,18 +43,75 @@ impl<'a> Commit<'a> {
     /// Conventional Commit specification.
     pub fn new(string: &'a str) -> Result<Self, Error> {
         let (ty, scope, breaking, description, body, trailers) =
-            parse::<VerboseError<&'a str>>(string).map_err(|err| (string, err))?;
+            parse(string).map_err(|err| (string, err))?;
+
+        let breaking_footer = trailers.iter().find(|(k, _, _)| is_breaking_change_key(k));
 
         Ok(Self {
             ty: ty.into(),
             scope: scope.map(Into::into),
             description: description.into(),
             body: body.map(Into::into),
-            breaking: breaking.is_some()
-                || trailers.iter().any(|(k, _, _)| k == &"BREAKING CHANGE"),
-            trailers: trailers.into_iter().map(Into::into).collect(),
+            breaking: breaking.is_some() || breaking_footer.is_some(),
+            breaking_description: breaking_footer
+                .map(|(_, _, v)| *v)
+                .or(if breaking.is_some() {
+                    Some(description)
+                } else {
+                    None
+                }),
+            trailers: trailers
+                .into_iter()
+                .map(|t| t.try_into().expect("the parser only ever emits known separators"))
+                .collect(),
         })
     }
+
+    /// The semantic-version bump implied by this commit, under the default
+    /// [`BumpPolicy`].
+    ///
+    /// Use [`BumpPolicy::bump`] directly for a custom policy, e.g. one that
+    /// also bumps the patch version for `perf` commits.
+    pub fn version_bump(&self) -> crate::VersionBump {
+        crate::BumpPolicy::default().bump(self)
+    }
+
+    /// All trailers attached to this commit, in the order they appeared.
+    ///
+    /// Unlike [`Simple::trailers`], this returns the typed [`Trailer`]s, whose
+    /// separator is a [`FooterSeparator`](crate::FooterSeparator) that tells
+    /// the [`ColonSpace`](crate::FooterSeparator::ColonSpace) and
+    /// [`SpacePound`](crate::FooterSeparator::SpacePound) forms apart, so a
+    /// caller can round-trip a commit exactly as it was written.
+    pub fn footers(&self) -> &[Trailer<'a>] {
+        &self.trailers
+    }
+
+    /// Every trailer whose key matches `token`, case-insensitively, in the
+    /// order they appeared.
+    ///
+    /// This makes it possible to collect all instances of a repeated
+    /// trailer - e.g. every `Reviewed-by:` entry, or every `Refs #` issue
+    /// reference - without manually filtering [`footers`](Self::footers).
+    pub fn footers_by_token<'b>(
+        &'b self,
+        token: &'b str,
+    ) -> impl Iterator<Item = Trailer<'a>> + 'b {
+        let token = FooterToken::new(token);
+        self.trailers
+            .iter()
+            .copied()
+            .filter(move |trailer| trailer.key() == token)
+    }
+}
+
+/// Is `key` the `BREAKING CHANGE` footer token, in either the form used in
+/// prose (`BREAKING CHANGE`) or the hyphenated form the spec allows so it can
+/// double as a real Git trailer key (`BREAKING-CHANGE`)?
+fn is_breaking_change_key(key: &str) -> bool {
+    let key = unicase::UniCase::new(key);
+    key == unicase::UniCase::new("BREAKING CHANGE")
+        || key == unicase::UniCase::new("BREAKING-CHANGE")
 }
 
 impl fmt::Display for Commit<'_> {
@@ -1,7 +1,6 @@
 //! Conventional Commit implementations.
 
 use crate::{Commit, SimpleTrailer};
-use std::ops::Deref;
 
 /// The weakly-typed variant of a commit.
 pub trait Simple {
@@ -33,6 +32,17 @@ pub trait Simple {
     ///   BREAKING CHANGE: this is a breaking change
     fn breaking(&self) -> bool;
 
+    /// The description of the breaking change, if any.
+    ///
+    /// This is the value of the `BREAKING CHANGE` (or `BREAKING-CHANGE`)
+    /// footer when one is present, or the commit's own [`description`] when
+    /// breaking was only signalled with the `!` marker. Returns `None` when
+    /// [`breaking`] is `false`.
+    ///
+    /// [`description`]: Simple::description
+    /// [`breaking`]: Simple::breaking
+    fn breaking_description(&self) -> Option<&str>;
+
     /// Any Git trailers.
     ///
     /// See: <https://git-scm.com/docs/git-interpret-trailers>
@@ -45,7 +55,7 @@ impl Simple for Commit<'_> {
     }
 
     fn scope(&self) -> Option<&str> {
-        self.scope.as_ref().map(Deref::deref)
+        self.scope.as_deref()
     }
 
     fn description(&self) -> &str {
@@ -53,13 +63,17 @@ impl Simple for Commit<'_> {
     }
 
     fn body(&self) -> Option<&str> {
-        self.body.as_ref().map(Deref::deref)
+        self.body.as_deref()
     }
 
     fn breaking(&self) -> bool {
         self.breaking
     }
 
+    fn breaking_description(&self) -> Option<&str> {
+        self.breaking_description
+    }
+
     fn trailers(&self) -> Vec<SimpleTrailer<'_>> {
         self.trailers
             .iter()
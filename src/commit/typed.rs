@@ -0,0 +1,71 @@
+//! A strongly-typed view over a [`Commit`]'s type.
+
+use crate::{Commit, Simple};
+
+/// The well-known Conventional Commit types.
+///
+/// Any type not recognised by this crate is surfaced as [`CommitType::Other`]
+/// so that callers never lose information present in the original message.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum CommitType<'a> {
+    /// A new feature.
+    Feat,
+
+    /// A bug fix.
+    Fix,
+
+    /// Documentation only changes.
+    Docs,
+
+    /// Changes that do not affect the meaning of the code.
+    Style,
+
+    /// A code change that neither fixes a bug nor adds a feature.
+    Refactor,
+
+    /// A code change that improves performance.
+    Perf,
+
+    /// Adding missing tests or correcting existing tests.
+    Test,
+
+    /// Changes to the build system or external dependencies.
+    Build,
+
+    /// Changes to CI configuration files and scripts.
+    Ci,
+
+    /// Other changes that don't modify source or test files.
+    Chore,
+
+    /// Reverts a previous commit.
+    Revert,
+
+    /// Any other, project-specific type.
+    Other(&'a str),
+}
+
+/// The strongly-typed variant of a commit.
+pub trait Typed {
+    /// The type of the commit.
+    fn ty(&self) -> CommitType<'_>;
+}
+
+impl Typed for Commit<'_> {
+    fn ty(&self) -> CommitType<'_> {
+        match self.type_() {
+            ty if ty.eq_ignore_ascii_case("feat") => CommitType::Feat,
+            ty if ty.eq_ignore_ascii_case("fix") => CommitType::Fix,
+            ty if ty.eq_ignore_ascii_case("docs") => CommitType::Docs,
+            ty if ty.eq_ignore_ascii_case("style") => CommitType::Style,
+            ty if ty.eq_ignore_ascii_case("refactor") => CommitType::Refactor,
+            ty if ty.eq_ignore_ascii_case("perf") => CommitType::Perf,
+            ty if ty.eq_ignore_ascii_case("test") => CommitType::Test,
+            ty if ty.eq_ignore_ascii_case("build") => CommitType::Build,
+            ty if ty.eq_ignore_ascii_case("ci") => CommitType::Ci,
+            ty if ty.eq_ignore_ascii_case("chore") => CommitType::Chore,
+            ty if ty.eq_ignore_ascii_case("revert") => CommitType::Revert,
+            ty => CommitType::Other(ty),
+        }
+    }
+}
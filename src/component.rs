@@ -55,17 +55,17 @@ pub struct SimpleFooter<'a> {
 impl<'a> SimpleFooter<'a> {
     /// The token of the footer.
     pub fn token(&self) -> &str {
-        &*self.footer.token
+        &self.footer.token
     }
 
     /// The separator between the footer token and its value.
     pub fn separator(&self) -> &str {
-        &*self.footer.sep
+        &self.footer.sep
     }
 
     /// The value of the footer.
     pub fn value(&self) -> &str {
-        &*self.footer.value
+        &self.footer.value
     }
 }
 
@@ -74,15 +74,13 @@ impl<'a> SimpleFooter<'a> {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "serde", serde(try_from = "&str"))]
 #[cfg_attr(feature = "serde", serde(into = "&'static str"))]
+#[non_exhaustive]
 pub enum FooterSeparator {
     /// ": "
     ColonSpace,
 
     /// " #"
     SpacePound,
-
-    #[doc(hidden)]
-    __NonExhaustive,
 }
 
 impl FooterSeparator {
@@ -91,7 +89,6 @@ impl FooterSeparator {
         match self {
             FooterSeparator::ColonSpace => ": ",
             FooterSeparator::SpacePound => " #",
-            FooterSeparator::__NonExhaustive => unreachable!(),
         }
     }
 }
@@ -110,9 +107,9 @@ impl Deref for FooterSeparator {
     }
 }
 
-impl Into<&'static str> for FooterSeparator {
-    fn into(self) -> &'static str {
-        self.as_str()
+impl From<FooterSeparator> for &'static str {
+    fn from(value: FooterSeparator) -> Self {
+        value.as_str()
     }
 }
 
@@ -129,12 +126,12 @@ impl FromStr for FooterSeparator {
         match sep {
             ": " => Ok(FooterSeparator::ColonSpace),
             " #" => Ok(FooterSeparator::SpacePound),
-            _ => Err(Error::new(ErrorKind::InvalidFormat)),
+            _ => Err(Error::new(ErrorKind::InvalidFooter)),
         }
     }
 }
 
-impl<'s> TryFrom<&'s str> for FooterSeparator {
+impl TryFrom<&str> for FooterSeparator {
     type Error = Error;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
@@ -219,6 +216,19 @@ macro_rules! unicase_components {
                     Self { value: unicase::UniCase::new(value) }
                 }
             }
+
+            // `unicase::UniCase` doesn't implement `serde::Serialize`, so
+            // these can't just `#[derive(Serialize)]` like the `components!`
+            // types; serialize as the wrapped string instead.
+            #[cfg(feature = "serde")]
+            impl Serialize for $ty<'_> {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    serializer.serialize_str(self)
+                }
+            }
         )+
     )
 }
@@ -226,3 +236,71 @@ macro_rules! unicase_components {
 components![Description, Body, FooterValue];
 
 unicase_components![Type, Scope, FooterToken];
+
+/// A single Git trailer, as found in the footer of a commit message.
+///
+/// This is conceptually the same shape as [`Footer`], but named to match the
+/// vocabulary used by [`crate::Commit`] and the [`crate::Simple`] trait.
+///
+/// See: <https://git-scm.com/docs/git-interpret-trailers>
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Trailer<'a> {
+    key: FooterToken<'a>,
+    sep: FooterSeparator,
+    value: FooterValue<'a>,
+}
+
+impl<'a> Trailer<'a> {
+    /// Piece together a trailer.
+    pub const fn new(key: FooterToken<'a>, sep: FooterSeparator, value: FooterValue<'a>) -> Self {
+        Self { key, sep, value }
+    }
+
+    /// The key of the trailer.
+    pub const fn key(&self) -> FooterToken<'a> {
+        self.key
+    }
+
+    /// The separator between the trailer key and its value.
+    pub const fn separator(&self) -> FooterSeparator {
+        self.sep
+    }
+
+    /// The value of the trailer.
+    pub const fn value(&self) -> FooterValue<'a> {
+        self.value
+    }
+}
+
+impl<'a> TryFrom<(&'a str, &'a str, &'a str)> for Trailer<'a> {
+    type Error = Error;
+
+    fn try_from((key, sep, value): (&'a str, &'a str, &'a str)) -> Result<Self, Self::Error> {
+        Ok(Self::new(FooterToken::new(key), sep.parse()?, FooterValue::new(value)))
+    }
+}
+
+/// The "simple trailer" variant, for convenient access to the string slice
+/// values of its components.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SimpleTrailer<'a> {
+    pub(crate) trailer: &'a Trailer<'a>,
+}
+
+impl<'a> SimpleTrailer<'a> {
+    /// The key of the trailer.
+    pub fn key(&self) -> &str {
+        &self.trailer.key
+    }
+
+    /// The separator between the trailer key and its value.
+    pub fn separator(&self) -> &str {
+        &self.trailer.sep
+    }
+
+    /// The value of the trailer.
+    pub fn value(&self) -> &str {
+        &self.trailer.value
+    }
+}
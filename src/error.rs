@@ -0,0 +1,98 @@
+//! Errors produced while parsing a conventional commit.
+
+use std::fmt;
+
+/// An error produced when parsing a conventional commit fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    input: String,
+    offset: usize,
+}
+
+impl Error {
+    /// Create an error with no positional context, e.g. for a value that
+    /// didn't come from parsing a full commit message.
+    pub(crate) fn new(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            input: String::new(),
+            offset: 0,
+        }
+    }
+
+    /// Create an error pointing at a specific byte offset within `input`.
+    pub(crate) fn with_context(kind: ErrorKind, input: &str, offset: usize) -> Self {
+        Self {
+            kind,
+            input: input.to_owned(),
+            offset,
+        }
+    }
+
+    /// The kind of error that occurred.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// The commit string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The byte offset into [`input`](Self::input) at which the error was
+    /// detected.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.offset)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl<'a> From<(&'a str, (ErrorKind, usize))> for Error {
+    fn from((input, (kind, offset)): (&'a str, (ErrorKind, usize))) -> Self {
+        Error::with_context(kind, input, offset)
+    }
+}
+
+/// The kind of parsing failure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The commit is missing its type, e.g. the message doesn't start with
+    /// an alphabetic type before `(`, `!`, or `:`.
+    MissingType,
+
+    /// The commit's scope is malformed, e.g. an unterminated `(`.
+    InvalidScope,
+
+    /// The commit is missing its description, e.g. there's no `: `
+    /// separator after the type/scope/`!`, or nothing follows it.
+    MissingDescription,
+
+    /// The description isn't followed by a blank line before the body or
+    /// footers begin.
+    MissingBlankLineAfterDescription,
+
+    /// A line in the footer section doesn't conform to the `token: value`
+    /// or `token #value` footer grammar.
+    InvalidFooter,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ErrorKind::MissingType => "missing type before `:`",
+            ErrorKind::InvalidScope => "invalid scope",
+            ErrorKind::MissingDescription => "missing description",
+            ErrorKind::MissingBlankLineAfterDescription => "missing blank line after description",
+            ErrorKind::InvalidFooter => "invalid footer",
+        };
+        f.write_str(message)
+    }
+}
@@ -0,0 +1,21 @@
+//! A parser for the [Conventional Commits](https://www.conventionalcommits.org)
+//! specification.
+
+mod component;
+mod error;
+mod parser;
+mod version;
+
+pub mod commit;
+pub mod lint;
+
+pub use crate::commit::simple::Simple;
+pub use crate::commit::typed::Typed;
+pub use crate::commit::Commit;
+pub use crate::component::{
+    Body, Description, Footer, FooterSeparator, FooterToken, FooterValue, Scope, SimpleFooter,
+    SimpleTrailer, Trailer, Type,
+};
+pub use crate::error::{Error, ErrorKind};
+pub use crate::lint::{Policy, Violation, ViolationCode};
+pub use crate::version::{BumpPolicy, VersionBump};
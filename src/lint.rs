@@ -0,0 +1,170 @@
+//! A configurable lint layer over a parsed [`Commit`].
+//!
+//! This is aimed at commit-gate use cases (CI checks, pre-receive hooks)
+//! that need to enforce project-specific rules beyond what the
+//! Conventional Commits grammar itself requires. Parsing and linting are
+//! kept separate: a message can be a perfectly valid Conventional Commit
+//! yet still be reported as non-conforming to a project's stricter
+//! [`Policy`].
+
+use crate::{Commit, Simple};
+
+/// A set of project-specific rules to check a [`Commit`] against.
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    allowed_types: Option<Vec<String>>,
+    required_scope: Option<String>,
+    max_description_len: Option<usize>,
+    banned_prefixes: Vec<String>,
+    breaking_requires_body: bool,
+}
+
+impl Policy {
+    /// Start from a policy with no rules enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only allow these commit types (case-insensitive).
+    pub fn allowed_types<I, S>(mut self, types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_types = Some(types.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Require every commit to declare this scope.
+    pub fn required_scope(mut self, scope: impl Into<String>) -> Self {
+        self.required_scope = Some(scope.into());
+        self
+    }
+
+    /// Cap the description at `len` characters.
+    pub fn max_description_len(mut self, len: usize) -> Self {
+        self.max_description_len = Some(len);
+        self
+    }
+
+    /// Ban commits whose type or description starts with any of `prefixes`
+    /// (case-insensitive), e.g. `wip` to catch both a `wip: ...` type and a
+    /// `chore: wip on x` description.
+    pub fn banned_prefixes<I, S>(mut self, prefixes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.banned_prefixes = prefixes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Require a body on every breaking commit.
+    pub fn breaking_requires_body(mut self, required: bool) -> Self {
+        self.breaking_requires_body = required;
+        self
+    }
+
+    /// Check `commit` against this policy, returning every violation found.
+    pub fn check(&self, commit: &Commit<'_>) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        if let Some(allowed) = &self.allowed_types {
+            if !allowed
+                .iter()
+                .any(|ty| ty.eq_ignore_ascii_case(commit.type_()))
+            {
+                violations.push(Violation::new(
+                    ViolationCode::DisallowedType,
+                    format!("type `{}` is not in the allowed list", commit.type_()),
+                ));
+            }
+        }
+
+        if let Some(required) = &self.required_scope {
+            if commit.scope() != Some(required.as_str()) {
+                violations.push(Violation::new(
+                    ViolationCode::MissingScope,
+                    format!("commit must use the `{required}` scope"),
+                ));
+            }
+        }
+
+        if let Some(max_len) = self.max_description_len {
+            if commit.description().chars().count() > max_len {
+                violations.push(Violation::new(
+                    ViolationCode::DescriptionTooLong,
+                    format!("description is longer than {max_len} characters"),
+                ));
+            }
+        }
+
+        if let Some(prefix) = self.banned_prefixes.iter().find(|prefix| {
+            let prefix = prefix.to_ascii_lowercase();
+            commit.type_().to_ascii_lowercase().starts_with(&prefix)
+                || commit.description().to_ascii_lowercase().starts_with(&prefix)
+        }) {
+            violations.push(Violation::new(
+                ViolationCode::BannedPrefix,
+                format!("type or description starts with the banned prefix `{prefix}`"),
+            ));
+        }
+
+        let has_body = commit.body().is_some_and(|body| !body.trim().is_empty());
+        if self.breaking_requires_body && commit.breaking() && !has_body {
+            violations.push(Violation::new(
+                ViolationCode::MissingBreakingBody,
+                "breaking changes must include a body explaining the change",
+            ));
+        }
+
+        violations
+    }
+}
+
+/// A single rule violation found by [`Policy::check`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Violation {
+    code: ViolationCode,
+    message: String,
+}
+
+impl Violation {
+    fn new(code: ViolationCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    /// A machine-readable code identifying the kind of violation, stable
+    /// across crate versions.
+    pub fn code(&self) -> ViolationCode {
+        self.code
+    }
+
+    /// A human-readable message describing the violation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// A machine-readable identifier for a [`Violation`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ViolationCode {
+    /// The commit's type isn't in [`Policy`]'s allow-list.
+    DisallowedType,
+
+    /// The commit is missing a scope that [`Policy`] requires.
+    MissingScope,
+
+    /// The commit's description is longer than [`Policy`] allows.
+    DescriptionTooLong,
+
+    /// The commit's type or description starts with one of [`Policy`]'s
+    /// banned prefixes, e.g. `wip`.
+    BannedPrefix,
+
+    /// The commit is breaking but has no body, which [`Policy`] requires.
+    MissingBreakingBody,
+}
@@ -0,0 +1,213 @@
+//! The `nom` grammar backing [`crate::Commit::new`].
+//!
+//! Grammar (informally):
+//!
+//! ```text
+//! commit ::= type ( '(' scope ')' )? '!'? ': ' description
+//!            ( '\n\n' body-paragraph )*
+//!            ( '\n\n' footer-line ( '\n' footer-line )* )*
+//! ```
+//!
+//! The header (`type`, `scope`, `description`, and the blank line that must
+//! follow it) is parsed with labelled [`context`] sub-parsers so that a
+//! failure can be traced back to the specific piece of grammar that didn't
+//! match; see [`locate`].
+
+use crate::error::ErrorKind;
+use nom::bytes::complete::{is_not, tag};
+use nom::character::complete::{alpha1, char, line_ending, not_line_ending};
+use nom::combinator::{complete, cut, opt, peek, verify};
+use nom::error::{context, ContextError, ParseError, VerboseError, VerboseErrorKind};
+use nom::sequence::{delimited, preceded};
+use nom::{IResult, Offset};
+
+/// The raw (key, separator, value) pieces of a single trailer line.
+pub(crate) type RawTrailer<'a> = (&'a str, &'a str, &'a str);
+
+/// The raw pieces parsed out of a commit message, before they are wrapped in
+/// [`crate::component`] newtypes.
+pub(crate) type ParsedCommit<'a> = (
+    &'a str,
+    Option<&'a str>,
+    Option<&'a str>,
+    &'a str,
+    Option<&'a str>,
+    Vec<RawTrailer<'a>>,
+);
+
+pub(crate) fn parse(input: &str) -> Result<ParsedCommit<'_>, (ErrorKind, usize)> {
+    let (_, (ty, scope, breaking, description, tail)) =
+        complete(header::<VerboseError<&str>>)(input).map_err(|err| locate(input, err))?;
+
+    let (body, trailers) = match tail {
+        Some(tail) => split_trailers(input, tail)?,
+        None => (None, Vec::new()),
+    };
+
+    Ok((ty, scope, breaking, description, body, trailers))
+}
+
+type Header<'a> = (
+    &'a str,
+    Option<&'a str>,
+    Option<&'a str>,
+    &'a str,
+    Option<&'a str>,
+);
+
+fn header<'a, E>(input: &'a str) -> IResult<&'a str, Header<'a>, E>
+where
+    E: ParseError<&'a str> + ContextError<&'a str>,
+{
+    let (input, ty) = context("type", ty)(input)?;
+    let (input, has_scope) = opt(peek(char('(')))(input)?;
+    let (input, scope) = if has_scope.is_some() {
+        let (input, scope) = context("scope", cut(scope))(input)?;
+        (input, Some(scope))
+    } else {
+        (input, None)
+    };
+    let (input, breaking) = opt(char('!'))(input)?;
+    let breaking = breaking.map(|_| "!");
+    let (input, description) =
+        context("description", cut(preceded(tag(": "), description)))(input)?;
+
+    // `description` stops at the end of the line, so what's left is either
+    // empty or starts with a line ending.
+    let (input, tail) = if input.is_empty() {
+        (input, None)
+    } else {
+        let (input, _) = context("blank line after description", line_ending)(input)?;
+        if input.is_empty() {
+            // A single trailing newline with nothing after it is just the
+            // end of a header-only commit, not a missing blank line.
+            (input, None)
+        } else {
+            let (input, _) =
+                context("blank line after description", cut(line_ending))(input)?;
+            (input, Some(input))
+        }
+    };
+
+    Ok((input, (ty, scope, breaking, description, tail)))
+}
+
+fn ty<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
+    alpha1(input)
+}
+
+fn scope<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
+    delimited(char('('), is_not(")"), char(')'))(input)
+}
+
+fn description<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, &'a str, E> {
+    verify(not_line_ending, |d: &str| !d.is_empty())(input)
+}
+
+/// Turn a failed header parse into the innermost labelled sub-parser that
+/// failed, plus the byte offset into `input` at which it failed.
+fn locate(input: &str, err: nom::Err<VerboseError<&str>>) -> (ErrorKind, usize) {
+    let err = match err {
+        nom::Err::Error(e) | nom::Err::Failure(e) => e,
+        nom::Err::Incomplete(_) => return (ErrorKind::MissingDescription, input.len()),
+    };
+
+    let (rest, kind) = err
+        .errors
+        .iter()
+        .find_map(|(rest, kind)| match kind {
+            VerboseErrorKind::Context(label) => Some((*rest, to_error_kind(label))),
+            _ => None,
+        })
+        .unwrap_or((input, ErrorKind::MissingType));
+
+    (kind, input.offset(rest))
+}
+
+fn to_error_kind(label: &str) -> ErrorKind {
+    match label {
+        "scope" => ErrorKind::InvalidScope,
+        "description" => ErrorKind::MissingDescription,
+        "blank line after description" => ErrorKind::MissingBlankLineAfterDescription,
+        _ => ErrorKind::MissingType,
+    }
+}
+
+/// The body (if any) and trailers split out of the commit's tail.
+type BodyAndTrailers<'a> = (Option<&'a str>, Vec<RawTrailer<'a>>);
+
+/// Split the remainder of a commit, following the header's trailing blank
+/// line, into an optional body and the trailers that follow it.
+///
+/// Trailing blank-line-delimited paragraphs are footers for as long as their
+/// first line is footer-shaped; the first paragraph (counting from the end)
+/// that isn't marks the end of the footer block, and everything up to and
+/// including it is the body. Once a paragraph has committed to being a
+/// footer, every one of its other lines is required to be footer-shaped too,
+/// so a typo in a trailer block is reported rather than silently folded into
+/// the body.
+fn split_trailers<'a>(
+    input: &'a str,
+    tail: &'a str,
+) -> Result<BodyAndTrailers<'a>, (ErrorKind, usize)> {
+    let paragraphs: Vec<&str> = tail.split("\n\n").collect();
+
+    // Walk paragraphs back-to-front, collecting each footer paragraph's
+    // trailers (in source order) until a non-footer paragraph is hit; then
+    // undo the paragraph-level reversal by popping them off in the other
+    // direction.
+    let mut footer_paragraphs = Vec::new();
+    for paragraph in paragraphs.iter().rev() {
+        match paragraph.lines().next() {
+            Some(first_line) if trailer_line(first_line).is_some() => {}
+            _ => break,
+        }
+
+        let mut trailers = Vec::new();
+        for line in paragraph.lines() {
+            match trailer_line(line) {
+                Some(trailer) => trailers.push(trailer),
+                None => return Err((ErrorKind::InvalidFooter, input.offset(line))),
+            }
+        }
+        footer_paragraphs.push(trailers);
+    }
+
+    let footer_paragraph_count = footer_paragraphs.len();
+    let trailers = footer_paragraphs.into_iter().rev().flatten().collect();
+
+    let body_paragraphs = &paragraphs[..paragraphs.len() - footer_paragraph_count];
+    // Stray blank-only trailing paragraphs (e.g. extra newlines after the
+    // header) aren't a body; look back past them for real content.
+    let body = body_paragraphs
+        .iter()
+        .rev()
+        .find(|paragraph| !paragraph.trim().is_empty())
+        .map(|last| {
+            let end = tail.offset(last) + last.len();
+            &tail[..end]
+        });
+
+    Ok((body, trailers))
+}
+
+fn trailer_line(line: &str) -> Option<RawTrailer<'_>> {
+    if let Some((key, value)) = line.split_once(": ") {
+        if !key.is_empty() && !key.contains(char::is_whitespace) {
+            return Some((key, ": ", value));
+        }
+        // `BREAKING CHANGE` is the one trailer key allowed to contain a
+        // space, per the Conventional Commits spec.
+        if key.eq_ignore_ascii_case("BREAKING CHANGE") {
+            return Some((key, ": ", value));
+        }
+    }
+
+    if let Some((key, value)) = line.split_once(" #") {
+        if !key.is_empty() && !key.contains(char::is_whitespace) {
+            return Some((key, " #", value));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,73 @@
+//! Semantic-version bump inference from a commit's type and breaking status.
+
+use crate::{Commit, Simple};
+
+/// The kind of semantic-version bump implied by a commit.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum VersionBump {
+    /// A breaking change: bump the major version.
+    Major,
+
+    /// A new, backwards-compatible feature: bump the minor version.
+    Minor,
+
+    /// A backwards-compatible bug fix: bump the patch version.
+    Patch,
+
+    /// A commit that doesn't affect the public API, e.g. `chore` or `docs`.
+    None,
+}
+
+/// A policy mapping a non-breaking commit's type to the [`VersionBump`] it
+/// implies.
+///
+/// [`BumpPolicy::default`] implements the mapping from the Conventional
+/// Commits spec (`feat` bumps minor, `fix` bumps patch, everything else
+/// doesn't bump); use [`BumpPolicy::with_type`] to register additional
+/// types, e.g. `perf` -> [`VersionBump::Patch`], without forking the crate.
+///
+/// A breaking change always bumps major, regardless of what's registered
+/// here; see [`BumpPolicy::bump`].
+#[derive(Debug, Clone)]
+pub struct BumpPolicy {
+    types: Vec<(String, VersionBump)>,
+}
+
+impl BumpPolicy {
+    /// Register the [`VersionBump`] for a non-breaking commit `type`,
+    /// overriding any previous registration for that type.
+    pub fn with_type(mut self, ty: &str, bump: VersionBump) -> Self {
+        match self
+            .types
+            .iter_mut()
+            .find(|(t, _)| t.eq_ignore_ascii_case(ty))
+        {
+            Some(existing) => existing.1 = bump,
+            None => self.types.push((ty.to_owned(), bump)),
+        }
+        self
+    }
+
+    /// The [`VersionBump`] implied by `commit` under this policy.
+    pub fn bump(&self, commit: &Commit<'_>) -> VersionBump {
+        if commit.breaking() {
+            return VersionBump::Major;
+        }
+
+        self.types
+            .iter()
+            .find(|(ty, _)| ty.eq_ignore_ascii_case(commit.type_()))
+            .map_or(VersionBump::None, |(_, bump)| *bump)
+    }
+}
+
+impl Default for BumpPolicy {
+    fn default() -> Self {
+        Self {
+            types: vec![
+                ("feat".to_owned(), VersionBump::Minor),
+                ("fix".to_owned(), VersionBump::Patch),
+            ],
+        }
+    }
+}